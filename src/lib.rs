@@ -6,6 +6,35 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Error, LitStr, parse_macro_input};
 
+/// Input for [`include_absolute_path!`]: a path literal, and an optional trailing
+/// `allow_missing` flag that opts out of the existence check.
+struct AbsolutePathInput {
+    path: LitStr,
+    allow_missing: bool,
+}
+
+impl syn::parse::Parse for AbsolutePathInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path: LitStr = input.parse()?;
+
+        let allow_missing = if input.peek(syn::Token![,]) {
+            input.parse::<syn::Token![,]>()?;
+            let flag: syn::Ident = input.parse()?;
+            if flag != "allow_missing" {
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format!("Unknown flag '{flag}', expected 'allow_missing'"),
+                ));
+            }
+            true
+        } else {
+            false
+        };
+
+        Ok(AbsolutePathInput { path, allow_missing })
+    }
+}
+
 /// Returns the absolute path of a file or directory at compile time.
 ///
 /// This macro resolves both relative and absolute paths. Relative paths are resolved
@@ -39,7 +68,8 @@ use syn::{Error, LitStr, parse_macro_input};
 /// # Panics
 ///
 /// This macro will panic at compile time if:
-/// - The specified file or directory does not exist
+/// - The specified file or directory does not exist (unless `allow_missing` is passed, see
+///   below)
 /// - The path contains invalid UTF-8 characters
 /// - Environment variable expansion fails
 /// - The path contains suspicious traversal patterns (security check)
@@ -50,6 +80,20 @@ use syn::{Error, LitStr, parse_macro_input};
 /// attempts. Paths with more than 3 `..` segments or where more than half the
 /// components are `..` will be rejected.
 ///
+/// # Allowing missing paths
+///
+/// Pass `allow_missing` as a second argument to opt out of the existence check, for paths
+/// like build outputs that the path doesn't exist yet but will exist later. When the path is
+/// absent, the macro falls back to a logical (purely lexical, symlink-unaware) normalization
+/// instead of failing:
+///
+/// ```rust,ignore
+/// use include_absolute_path::include_absolute_path;
+///
+/// // Does not need to exist yet; still gets env expansion and the traversal check.
+/// const OUT_FILE: &str = include_absolute_path!("../target/gen.rs", allow_missing);
+/// ```
+///
 /// # Examples
 ///
 /// ## Relative path resolution
@@ -85,25 +129,358 @@ use syn::{Error, LitStr, parse_macro_input};
 /// ```
 #[proc_macro]
 pub fn include_absolute_path(input: TokenStream) -> TokenStream {
+    // Parse the input tokens into a path literal and an optional `allow_missing` flag
+    let AbsolutePathInput { path: lit_str, allow_missing } =
+        parse_macro_input!(input as AbsolutePathInput);
+    let path = lit_str.value();
+    let span = lit_str.span();
+
+    let caller_file = get_caller_file();
+
+    let raw_path = match resolve_raw_path(&path, span, &caller_file) {
+        Ok(raw_path) => raw_path,
+        Err(compile_error) => return compile_error,
+    };
+
+    // Canonicalize the path. If the caller opted into `allow_missing` and the path is simply
+    // absent, fall back to a logical normalization instead of failing; any other error (e.g.
+    // a permission failure) still panics with the usual diagnostic.
+    let absolute_path = match raw_path.canonicalize() {
+        Ok(path) => path,
+        Err(e) if allow_missing && e.kind() == std::io::ErrorKind::NotFound => {
+            logical_normalize(&absolutize(raw_path))
+        }
+        Err(_) => canonicalize_or_panic(&raw_path),
+    };
+
+    // Convert the path to a string
+    let absolute_path_str = absolute_path.to_str().unwrap_or_else(|| {
+        panic!(
+            "Path '{}' contains invalid UTF-8 characters. \
+            This is common on systems with non-UTF-8 file paths. \
+            Consider using ASCII-only paths.",
+            absolute_path.display()
+        )
+    });
+
+    // Strip the Windows verbatim (`\\?\`) prefix so the emitted constant holds
+    // a conventional path that downstream consumers can shell out with or re-parse.
+    let normalized_path_str = strip_verbatim_prefix(absolute_path_str);
+
+    // Return the absolute path as a string literal
+    let expanded = quote! {
+        #normalized_path_str
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Returns the lexically-normalized absolute path of a file or directory at compile time,
+/// without consulting the filesystem to resolve it.
+///
+/// Unlike [`include_absolute_path!`], this macro does not require the path to exist and does
+/// not follow symlinks: `.` and `..` components are folded purely by manipulating path
+/// components, so `foo/bar/..` always becomes `foo`, even if `foo/bar` is a symlink pointing
+/// elsewhere. This is useful for paths under build output trees that may not exist yet, or
+/// where following symlinks would resolve to a different directory than intended.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// use include_absolute_path::include_absolute_path_logical;
+///
+/// // Absolute, normalized path of the (possibly not-yet-created) build output directory
+/// const OUT_DIR: &str = include_absolute_path_logical!("../target/generated");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+/// - The path contains invalid UTF-8 characters
+/// - Environment variable expansion fails
+/// - The path contains suspicious traversal patterns (security check, see
+///   [`include_absolute_path!`])
+#[proc_macro]
+pub fn include_absolute_path_logical(input: TokenStream) -> TokenStream {
     // Parse the input tokens into a string
     let lit_str = parse_macro_input!(input as LitStr);
     let path = lit_str.value();
     let span = lit_str.span();
 
-    // Get the file path where macro is called
-    let caller_file_str = proc_macro::Span::call_site()
+    let caller_file = get_caller_file();
+
+    let raw_path = match resolve_raw_path(&path, span, &caller_file) {
+        Ok(raw_path) => raw_path,
+        Err(compile_error) => return compile_error,
+    };
+
+    let logical_path = logical_normalize(&absolutize(raw_path));
+
+    // Convert the path to a string
+    let logical_path_str = logical_path.to_str().unwrap_or_else(|| {
+        panic!(
+            "Path '{}' contains invalid UTF-8 characters. \
+            This is common on systems with non-UTF-8 file paths. \
+            Consider using ASCII-only paths.",
+            logical_path.display()
+        )
+    });
+
+    let normalized_path_str = strip_verbatim_prefix(logical_path_str);
+
+    // Return the absolute path as a string literal
+    let expanded = quote! {
+        #normalized_path_str
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Join `path` onto the current working directory if it isn't already absolute.
+///
+/// Used ahead of [`logical_normalize`], which only folds `.`/`..` components and otherwise
+/// leaves a relative path relative.
+fn absolutize(path: PathBuf) -> PathBuf {
+    if path.is_absolute() {
+        path
+    } else {
+        let cwd = std::env::current_dir()
+            .unwrap_or_else(|e| panic!("Failed to get current working directory: {e}."));
+        cwd.join(path)
+    }
+}
+
+/// Fold `.` and `..` components of `path` purely lexically, without touching the filesystem
+/// or resolving symlinks.
+///
+/// `Prefix` resets the stack (a new prefix, e.g. a Windows drive letter, replaces whatever
+/// came before it). `RootDir` clears the stack down to a leading `Prefix` if one is already
+/// present (so `C:\` keeps its drive letter instead of losing it), otherwise clears entirely.
+/// `CurDir` is skipped, `Normal` components are pushed, and `ParentDir` pops the last pushed
+/// `Normal` component. A `ParentDir` at the root (the stack's top is `RootDir`/`Prefix`) is
+/// dropped rather than popped, so the result never climbs above the root; the only time
+/// `ParentDir` is pushed literally is when the stack is empty or its top is itself a
+/// `ParentDir` (which can happen when `path` is relative).
+fn logical_normalize(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut stack: Vec<Component> = Vec::new();
+
+    for component in path.components() {
+        match component {
+            Component::Prefix(_) => {
+                stack.clear();
+                stack.push(component);
+            }
+            Component::RootDir => {
+                if matches!(stack.first(), Some(Component::Prefix(_))) {
+                    stack.truncate(1);
+                } else {
+                    stack.clear();
+                }
+                stack.push(component);
+            }
+            Component::CurDir => {}
+            Component::Normal(_) => stack.push(component),
+            Component::ParentDir => match stack.last() {
+                Some(Component::Normal(_)) => {
+                    stack.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => stack.push(component),
+            },
+        }
+    }
+
+    stack.into_iter().collect()
+}
+
+/// Input for [`include_relative_path!`]: a target path and the base directory it should be
+/// expressed relative to, separated by a comma.
+struct RelativePathInput {
+    target: LitStr,
+    base: LitStr,
+}
+
+impl syn::parse::Parse for RelativePathInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let target: LitStr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let base: LitStr = input.parse()?;
+        Ok(RelativePathInput { target, base })
+    }
+}
+
+/// Returns the path of a file or directory, expressed relative to a base directory, at
+/// compile time.
+///
+/// Both `target` and `base` are resolved the same way as [`include_absolute_path!`]
+/// (relative-path joining against the caller file, environment variable expansion, and the
+/// traversal security check), then canonicalized and compared component-by-component to
+/// produce a relative path. This is useful for generating portable manifests or include
+/// directives that shouldn't bake in an absolute machine path.
+///
+/// # Usage
+///
+/// ```rust,ignore
+/// use include_absolute_path::include_relative_path;
+///
+/// // If called from src/main.rs, resolves to "src/lib.rs" (relative to the crate root)
+/// const LIB_RS: &str = include_relative_path!("lib.rs", "$CARGO_MANIFEST_DIR");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic at compile time if:
+/// - Either path does not exist
+/// - The path contains invalid UTF-8 characters
+/// - Environment variable expansion fails
+/// - The path contains suspicious traversal patterns (security check)
+///
+/// It is a compile error if `target` and `base` live on different filesystem roots (e.g.
+/// different Windows drives), since no relative path exists between them.
+#[proc_macro]
+pub fn include_relative_path(input: TokenStream) -> TokenStream {
+    let RelativePathInput { target, base } = parse_macro_input!(input as RelativePathInput);
+    let target_str = target.value();
+    let target_span = target.span();
+    let base_str = base.value();
+    let base_span = base.span();
+
+    let caller_file = get_caller_file();
+
+    let target_raw = match resolve_raw_path(&target_str, target_span, &caller_file) {
+        Ok(raw_path) => raw_path,
+        Err(compile_error) => return compile_error,
+    };
+    let base_raw = match resolve_raw_path(&base_str, base_span, &caller_file) {
+        Ok(raw_path) => raw_path,
+        Err(compile_error) => return compile_error,
+    };
+
+    let target_canonical = canonicalize_or_panic(&target_raw);
+    let base_canonical = canonicalize_or_panic(&base_raw);
+
+    let relative_path = match relativize(&target_canonical, &base_canonical) {
+        Some(relative_path) => relative_path,
+        None => {
+            return Error::new(
+                target_span,
+                format!(
+                    "Cannot express '{}' relative to '{}': the paths are on different filesystem roots.",
+                    target_canonical.display(),
+                    base_canonical.display()
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    // Convert the path to a string
+    let relative_path_str = relative_path.to_str().unwrap_or_else(|| {
+        panic!(
+            "Path '{}' contains invalid UTF-8 characters. \
+            This is common on systems with non-UTF-8 file paths. \
+            Consider using ASCII-only paths.",
+            relative_path.display()
+        )
+    });
+
+    // Return the relative path as a string literal
+    let expanded = quote! {
+        #relative_path_str
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Canonicalize `raw_path`, panicking with the same diagnostic as [`include_absolute_path!`]
+/// if the path cannot be resolved.
+fn canonicalize_or_panic(raw_path: &Path) -> PathBuf {
+    raw_path.canonicalize().unwrap_or_else(|e| {
+        let cwd = std::env::current_dir()
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+        panic!(
+            "Failed to resolve path '{}': {e}. \
+            Make sure the file or directory exists and is accessible. \
+            Current working directory: {cwd}",
+            raw_path.display()
+        )
+    })
+}
+
+/// Express `target` relative to `base` by zipping their components until they diverge,
+/// emitting one `..` for each remaining `base` component, then appending the remaining
+/// `target` components. Returns `None` if the two paths don't share a common root (e.g.
+/// different Windows drives), since no relative path exists between them.
+fn relativize(target: &Path, base: &Path) -> Option<PathBuf> {
+    let target_components: Vec<_> = target.components().collect();
+    let base_components: Vec<_> = base.components().collect();
+
+    if target_components.first() != base_components.first() {
+        return None;
+    }
+
+    let mut common = 0;
+    while common < target_components.len()
+        && common < base_components.len()
+        && target_components[common] == base_components[common]
+    {
+        common += 1;
+    }
+
+    let mut relative = PathBuf::new();
+    for _ in &base_components[common..] {
+        relative.push("..");
+    }
+    for component in &target_components[common..] {
+        relative.push(component.as_os_str());
+    }
+
+    Some(relative)
+}
+
+/// Strip the Windows verbatim UNC prefix (`\\?\` or `\\?\UNC\`) from `path_str`,
+/// leaving the path unchanged on other platforms.
+fn strip_verbatim_prefix(path_str: &str) -> String {
+    if cfg!(windows) {
+        if let Some(rest) = path_str.strip_prefix(r"\\?\UNC\") {
+            return format!(r"\\{rest}");
+        }
+        if let Some(rest) = path_str.strip_prefix(r"\\?\") {
+            return rest.to_string();
+        }
+    }
+
+    path_str.to_string()
+}
+
+/// Get the file path where the macro is called.
+fn get_caller_file() -> PathBuf {
+    proc_macro::Span::call_site()
         .local_file()
         .unwrap_or_else(|| {
             panic!(
                 "Failed to get the source file location. \
             This should not happen on stable Rust."
             )
-        });
-
-    let caller_file = Path::new(&caller_file_str);
+        })
+}
 
+/// Expand environment variables in `path`, validate it for suspicious traversal patterns,
+/// and join it onto `caller_file`'s parent directory if it isn't already absolute.
+///
+/// This is the resolution step shared by every `include_*!` macro, before each macro applies
+/// its own filesystem validation (canonicalization, logical normalization, ...).
+fn resolve_raw_path(
+    path: &str,
+    span: proc_macro2::Span,
+    caller_file: &Path,
+) -> Result<PathBuf, TokenStream> {
     // Expand environment variables in the path
-    let expanded_path = match shellexpand::env(&path) {
+    let expanded_path = match shellexpand::env(path) {
         Ok(expanded) => expanded,
         Err(e) => panic!(
             "Failed to expand environment variable in path '{path}': {e}. \
@@ -116,7 +493,7 @@ pub fn include_absolute_path(input: TokenStream) -> TokenStream {
 
     // Validate for suspicious path patterns
     if contains_suspicious_patterns(&path_buf) {
-        return Error::new(
+        return Err(Error::new(
             span,
             format!(
                 "Path '{path}' contains suspicious traversal patterns. \
@@ -124,7 +501,7 @@ pub fn include_absolute_path(input: TokenStream) -> TokenStream {
             ),
         )
         .to_compile_error()
-        .into();
+        .into());
     }
 
     // Check if the path is absolute
@@ -143,38 +520,7 @@ pub fn include_absolute_path(input: TokenStream) -> TokenStream {
         parent.join(&path_buf)
     };
 
-    // Canonicalize the path
-    let absolute_path = match raw_path.canonicalize() {
-        Ok(path) => path,
-        Err(e) => {
-            let cwd = std::env::current_dir()
-                .map(|p| p.display().to_string())
-                .unwrap_or_else(|_| "<unknown>".to_string());
-            panic!(
-                "Failed to resolve path '{}': {e}. \
-                Make sure the file or directory exists and is accessible. \
-                Current working directory: {cwd}",
-                raw_path.display()
-            )
-        }
-    };
-
-    // Convert the path to a string
-    let absolute_path_str = absolute_path.to_str().unwrap_or_else(|| {
-        panic!(
-            "Path '{}' contains invalid UTF-8 characters. \
-            This is common on systems with non-UTF-8 file paths. \
-            Consider using ASCII-only paths.",
-            absolute_path.display()
-        )
-    });
-
-    // Return the absolute path as a string literal
-    let expanded = quote! {
-        #absolute_path_str
-    };
-
-    TokenStream::from(expanded)
+    Ok(raw_path)
 }
 
 /// Check if a path contains suspicious traversal patterns
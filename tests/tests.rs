@@ -1,4 +1,6 @@
-use include_absolute_path::include_absolute_path;
+use include_absolute_path::{
+    include_absolute_path, include_absolute_path_logical, include_relative_path,
+};
 
 #[test]
 fn test_absolute_include_absolute_path() {
@@ -29,3 +31,50 @@ fn test_containing_env_variable_with_subpath_include_absolute_path() {
     let expected = expected_canocalized.to_str().unwrap();
     assert_eq!(ACTUAL, expected);
 }
+
+#[test]
+fn test_relative_include_absolute_path_logical() {
+    const PATH: &str = include_absolute_path_logical!("non_existent_dir/gen.rs");
+    assert!(std::path::Path::new(PATH).ends_with(std::path::Path::new("non_existent_dir/gen.rs")));
+    assert!(std::path::Path::new(PATH).is_absolute());
+}
+
+#[test]
+fn test_parent_dir_folding_include_absolute_path_logical() {
+    const PATH: &str = include_absolute_path_logical!("does_not_exist/child/../sibling");
+    assert!(std::path::Path::new(PATH).ends_with(std::path::Path::new("does_not_exist/sibling")));
+}
+
+#[test]
+fn test_parent_dir_at_root_does_not_escape_include_absolute_path_logical() {
+    const PATH: &str = include_absolute_path_logical!("/a/../../b");
+    let expected = format!("{}b", std::path::MAIN_SEPARATOR);
+    assert_eq!(PATH, expected);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_drive_letter_preserved_include_absolute_path_logical() {
+    const PATH: &str = include_absolute_path_logical!("C:\\does_not_exist\\child\\..\\..\\sibling");
+    assert_eq!(PATH, "C:\\sibling");
+}
+
+#[test]
+fn test_include_relative_path() {
+    const PATH: &str = include_relative_path!("test_file.txt", "$CARGO_MANIFEST_DIR");
+    assert_eq!(PATH, "tests/test_file.txt");
+}
+
+#[test]
+fn test_allow_missing_include_absolute_path() {
+    const PATH: &str = include_absolute_path!("non_existent_dir/gen.rs", allow_missing);
+    assert!(PATH.ends_with("non_existent_dir/gen.rs"));
+    assert!(std::path::Path::new(PATH).is_absolute());
+}
+
+#[test]
+fn test_allow_missing_include_absolute_path_existing_file() {
+    const PATH: &str = include_absolute_path!("test_file.txt", allow_missing);
+    let contents = std::fs::read_to_string(PATH).unwrap();
+    assert_eq!(contents, "Hello World!");
+}